@@ -0,0 +1,55 @@
+use serde::Deserialize;
+
+const DEFAULT_CONFIG_PATH: &str = "config.toml";
+const DEFAULT_BIND_ADDRESS: &str = "127.0.0.1:8000";
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub database_url: String,
+    pub bind_address: String,
+    pub max_connections: u32,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FileConfig {
+    database_url: Option<String>,
+    bind_address: Option<String>,
+    max_connections: Option<u32>,
+}
+
+impl Config {
+    /// Loads configuration from `config.toml` (or the path in `CONFIG_PATH`),
+    /// letting `DATABASE_URL`, `BIND_ADDRESS` and `MAX_CONNECTIONS` env vars
+    /// override whatever the file sets.
+    pub fn load() -> Self {
+        let config_path =
+            std::env::var("CONFIG_PATH").unwrap_or_else(|_| DEFAULT_CONFIG_PATH.to_string());
+        let file_config = std::fs::read_to_string(&config_path)
+            .ok()
+            .map(|contents| {
+                toml::from_str(&contents)
+                    .unwrap_or_else(|e| panic!("Failed to parse {}: {}", config_path, e))
+            })
+            .unwrap_or_default();
+
+        Config {
+            database_url: env_override("DATABASE_URL", file_config.database_url)
+                .expect("DATABASE_URL must be set via config.toml or the environment."),
+            bind_address: env_override("BIND_ADDRESS", file_config.bind_address)
+                .unwrap_or_else(|| DEFAULT_BIND_ADDRESS.to_string()),
+            max_connections: std::env::var("MAX_CONNECTIONS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .or(file_config.max_connections)
+                .unwrap_or_else(default_max_connections),
+        }
+    }
+}
+
+fn env_override(key: &str, file_value: Option<String>) -> Option<String> {
+    std::env::var(key).ok().or(file_value)
+}
+
+fn default_max_connections() -> u32 {
+    num_cpus::get() as u32 * 2
+}
@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use sqlx::types::Uuid;
+
+use crate::models::DBError;
+use crate::shortid;
+
+/// Opaque keyset-pagination cursor.
+///
+/// Carries both the sort key (`created_at`) and the row's own uuid as a
+/// tiebreaker, so rows that share a `created_at` timestamp are never
+/// skipped or duplicated across a page boundary. Both are packed through
+/// the same sqids codec used for public ids, so `next_cursor` is an opaque
+/// token rather than a readable timestamp and raw uuid. The codec uses the
+/// default sqids alphabet, so a cursor is not signed: any sqid that decodes to
+/// three numbers is accepted as a cursor.
+#[derive(Debug, Clone, Copy)]
+pub struct Cursor {
+    pub created_at: DateTime<Utc>,
+    pub uuid: Uuid,
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        let micros = self.created_at.timestamp_micros() as u64;
+        let bytes = self.uuid.as_bytes();
+        let high = u64::from_be_bytes(bytes[0..8].try_into().unwrap());
+        let low = u64::from_be_bytes(bytes[8..16].try_into().unwrap());
+        shortid::sqids()
+            .encode(&[micros, high, low])
+            .expect("cursor-derived numbers always encode")
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, DBError> {
+        let numbers = shortid::sqids().decode(cursor);
+        let [micros, high, low]: [u64; 3] = numbers
+            .try_into()
+            .map_err(|_| DBError::InvalidUUID(format!("Invalid cursor: {}", cursor)))?;
+
+        let created_at = DateTime::<Utc>::from_timestamp_micros(micros as i64)
+            .ok_or_else(|| DBError::InvalidUUID(format!("Invalid cursor: {}", cursor)))?;
+
+        let mut uuid_bytes = [0u8; 16];
+        uuid_bytes[0..8].copy_from_slice(&high.to_be_bytes());
+        uuid_bytes[8..16].copy_from_slice(&low.to_be_bytes());
+
+        Ok(Cursor {
+            created_at,
+            uuid: Uuid::from_bytes(uuid_bytes),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_cursor() -> Cursor {
+        Cursor {
+            created_at: DateTime::<Utc>::from_timestamp_micros(1_700_000_000_123_456).unwrap(),
+            uuid: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn round_trips_a_cursor() {
+        let cursor = sample_cursor();
+        let decoded = Cursor::decode(&cursor.encode()).unwrap();
+        assert_eq!(decoded.created_at, cursor.created_at);
+        assert_eq!(decoded.uuid, cursor.uuid);
+    }
+
+    #[test]
+    fn encoded_cursor_does_not_contain_the_raw_uuid_or_timestamp() {
+        let cursor = sample_cursor();
+        let encoded = cursor.encode();
+        assert!(!encoded.contains(&cursor.uuid.to_string()));
+        assert!(!encoded.contains(&cursor.created_at.to_rfc3339()));
+    }
+
+    #[test]
+    fn rejects_a_cursor_that_does_not_decode_to_three_numbers() {
+        // A sqid encoding two numbers, not the three this codec expects.
+        let two_number_cursor = shortid::sqids().encode(&[1, 2]).unwrap();
+        assert!(Cursor::decode(&two_number_cursor).is_err());
+    }
+
+    #[test]
+    fn rejects_garbage_input() {
+        assert!(Cursor::decode("not-a-valid-cursor!!").is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use argon2::{
+    Argon2, PasswordHash, PasswordHasher, PasswordVerifier,
+    password_hash::{SaltString, rand_core::OsRng},
+};
+use axum::{
+    Json, extract::FromRequestParts, extract::State, http::StatusCode, http::request::Parts,
+};
+use axum_extra::extract::cookie::{Cookie, CookieJar, SameSite};
+
+use crate::{AppState, models::*};
+
+use super::handlers_inner::HandlerError;
+
+pub const SESSION_COOKIE_NAME: &str = "session_id";
+
+pub struct RequireUser(pub UserDetail);
+
+impl FromRequestParts<AppState> for RequireUser {
+    type Rejection = HandlerError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let jar = CookieJar::from_headers(&parts.headers);
+        let session_id = jar
+            .get(SESSION_COOKIE_NAME)
+            .map(|cookie| cookie.value().to_string())
+            .ok_or_else(|| {
+                HandlerError::unauthorized("not-authenticated", "Missing session cookie")
+            })?;
+
+        let session = state
+            .sessions_dao
+            .get_session(session_id)
+            .await
+            .map_err(|e| HandlerError::internal("internal-error", e.to_string()))?
+            .ok_or_else(|| {
+                HandlerError::unauthorized("invalid-session", "Invalid or expired session")
+            })?;
+
+        let user = state
+            .users_dao
+            .get_user_by_uuid(session.user_uuid)
+            .await
+            .map_err(|e| HandlerError::internal("internal-error", e.to_string()))?
+            .ok_or_else(|| {
+                HandlerError::unauthorized("invalid-session", "Invalid or expired session")
+            })?;
+
+        Ok(RequireUser(user.into()))
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/register",
+    request_body = User,
+    responses(
+        (status = 200, description = "User registered", body = UserDetail),
+        (status = 400, description = "Invalid registration payload"),
+        (status = 409, description = "Username already taken"),
+        (status = 500, description = "Internal server error"),
+    )
+)]
+pub async fn register(
+    State(AppState { users_dao, .. }): State<AppState>,
+    Json(user): Json<User>,
+) -> Result<Json<UserDetail>, HandlerError> {
+    if user.username.is_empty() {
+        return Err(HandlerError::bad_request(
+            "username-required",
+            "Username is required",
+        ));
+    }
+    if user.password.is_empty() {
+        return Err(HandlerError::bad_request(
+            "password-required",
+            "Password is required",
+        ));
+    }
+
+    let salt = SaltString::generate(&mut OsRng);
+    let password_hash = Argon2::default()
+        .hash_password(user.password.as_bytes(), &salt)
+        .map_err(|e| HandlerError::internal("internal-error", e.to_string()))?
+        .to_string();
+
+    let record = users_dao
+        .create_user(user, password_hash)
+        .await
+        .map_err(|e| match e {
+            DBError::Conflict(msg) => HandlerError::conflict("username-taken", msg),
+            e => HandlerError::internal("internal-error", e.to_string()),
+        })?;
+
+    Ok(Json(record.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/login",
+    request_body = User,
+    responses(
+        (status = 200, description = "Logged in", body = UserDetail),
+        (status = 401, description = "Invalid credentials"),
+        (status = 500, description = "Internal server error"),
+    )
+)]
+pub async fn login(
+    State(AppState {
+        users_dao,
+        sessions_dao,
+        ..
+    }): State<AppState>,
+    jar: CookieJar,
+    Json(credentials): Json<User>,
+) -> Result<(CookieJar, Json<UserDetail>), HandlerError> {
+    let record = users_dao
+        .get_user_by_username(credentials.username)
+        .await
+        .map_err(|e| HandlerError::internal("internal-error", e.to_string()))?
+        .ok_or_else(|| {
+            HandlerError::unauthorized("invalid-credentials", "Invalid username or password")
+        })?;
+
+    let parsed_hash = PasswordHash::new(&record.password_hash)
+        .map_err(|e| HandlerError::internal("internal-error", e.to_string()))?;
+    Argon2::default()
+        .verify_password(credentials.password.as_bytes(), &parsed_hash)
+        .map_err(|_| {
+            HandlerError::unauthorized("invalid-credentials", "Invalid username or password")
+        })?;
+
+    let session = sessions_dao
+        .create_session(record.user_uuid.clone())
+        .await
+        .map_err(|e| HandlerError::internal("internal-error", e.to_string()))?;
+
+    let cookie = Cookie::build((SESSION_COOKIE_NAME, session.session_id))
+        .path("/")
+        .http_only(true)
+        .same_site(SameSite::Lax)
+        .secure(true)
+        .build();
+
+    Ok((jar.add(cookie), Json(record.into())))
+}
+
+#[utoipa::path(
+    post,
+    path = "/logout",
+    responses(
+        (status = 200, description = "Logged out"),
+    )
+)]
+pub async fn logout(
+    State(AppState { sessions_dao, .. }): State<AppState>,
+    jar: CookieJar,
+) -> Result<(CookieJar, StatusCode), HandlerError> {
+    if let Some(cookie) = jar.get(SESSION_COOKIE_NAME) {
+        sessions_dao
+            .delete_session(cookie.value().to_string())
+            .await
+            .map_err(|e| HandlerError::internal("internal-error", e.to_string()))?;
+    }
+
+    Ok((jar.remove(Cookie::from(SESSION_COOKIE_NAME)), StatusCode::OK))
+}
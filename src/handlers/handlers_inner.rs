@@ -0,0 +1,62 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum HandlerError {
+    #[error("{message}")]
+    BadRequest { code: &'static str, message: String },
+    #[error("{message}")]
+    Unauthorized { code: &'static str, message: String },
+    #[error("{message}")]
+    NotFound { code: &'static str, message: String },
+    #[error("{message}")]
+    Conflict { code: &'static str, message: String },
+    #[error("{message}")]
+    InternalError { code: &'static str, message: String },
+}
+
+impl HandlerError {
+    pub fn bad_request(code: &'static str, message: impl Into<String>) -> Self {
+        Self::BadRequest {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn unauthorized(code: &'static str, message: impl Into<String>) -> Self {
+        Self::Unauthorized {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(code: &'static str, message: impl Into<String>) -> Self {
+        Self::NotFound {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn conflict(code: &'static str, message: impl Into<String>) -> Self {
+        Self::Conflict {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(code: &'static str, message: impl Into<String>) -> Self {
+        Self::InternalError {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            HandlerError::BadRequest { code, .. }
+            | HandlerError::Unauthorized { code, .. }
+            | HandlerError::NotFound { code, .. }
+            | HandlerError::Conflict { code, .. }
+            | HandlerError::InternalError { code, .. } => code,
+        }
+    }
+}
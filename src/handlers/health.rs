@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::AppState;
+
+const DB_PING_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HealthStatus {
+    pub status: &'static str,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReadinessStatus {
+    pub status: &'static str,
+    pub database: &'static str,
+}
+
+#[utoipa::path(
+    get,
+    path = "/health",
+    responses(
+        (status = 200, description = "Process is up", body = HealthStatus),
+    )
+)]
+pub async fn liveness() -> Json<HealthStatus> {
+    Json(HealthStatus { status: "ok" })
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/db",
+    responses(
+        (status = 200, description = "Database is reachable", body = ReadinessStatus),
+        (status = 503, description = "Database is unreachable", body = ReadinessStatus),
+    )
+)]
+pub async fn readiness(State(AppState { db_pool, .. }): State<AppState>) -> impl IntoResponse {
+    let probe = tokio::time::timeout(DB_PING_TIMEOUT, sqlx::query("SELECT 1").execute(&db_pool)).await;
+
+    match probe {
+        Ok(Ok(_)) => (
+            StatusCode::OK,
+            Json(ReadinessStatus {
+                status: "ok",
+                database: "up",
+            }),
+        ),
+        _ => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ReadinessStatus {
+                status: "unavailable",
+                database: "down",
+            }),
+        ),
+    }
+}
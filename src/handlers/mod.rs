@@ -1,141 +1,297 @@
-use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
-use sqlx::types::Uuid;
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde::Serialize;
+use utoipa::ToSchema;
 
-use crate::{AppState, models::*};
+use crate::{AppState, models::*, persistance::jobs_dao::NOTIFICATION_QUEUE, shortid};
 
+pub mod auth;
+pub mod health;
 mod handlers_inner;
 
-impl IntoResponse for handlers_inner::HandlerError {
+use auth::RequireUser;
+use handlers_inner::HandlerError;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorBody {
+    pub code: String,
+    pub message: String,
+}
+
+impl IntoResponse for HandlerError {
     fn into_response(self) -> axum::response::Response {
-        match self {
-            handlers_inner::HandlerError::BadRequest(msg) => {
-                (StatusCode::BAD_REQUEST, msg).into_response()
-            }
-            handlers_inner::HandlerError::InternalError(msg) => {
-                (StatusCode::INTERNAL_SERVER_ERROR, msg).into_response()
-            }
-        }
+        let status = match &self {
+            HandlerError::BadRequest { .. } => StatusCode::BAD_REQUEST,
+            HandlerError::Unauthorized { .. } => StatusCode::UNAUTHORIZED,
+            HandlerError::NotFound { .. } => StatusCode::NOT_FOUND,
+            HandlerError::Conflict { .. } => StatusCode::CONFLICT,
+            HandlerError::InternalError { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        let body = ErrorBody {
+            code: self.code().to_string(),
+            message: self.to_string(),
+        };
+        (status, Json(body)).into_response()
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/question",
+    request_body = Question,
+    responses(
+        (status = 200, description = "Question created", body = QuestionDetail),
+        (status = 400, description = "Invalid question payload"),
+        (status = 500, description = "Internal server error"),
+    )
+)]
 pub async fn create_question(
     State(AppState { questions_dao, .. }): State<AppState>,
+    RequireUser(user): RequireUser,
     Json(question): Json<Question>,
-) -> Result<Json<QuestionDetail>, handlers_inner::HandlerError> {
+) -> Result<Json<QuestionDetail>, HandlerError> {
     if question.title.is_empty() {
-        return Err(handlers_inner::HandlerError::BadRequest(
-            "Title is required".to_string(),
+        return Err(HandlerError::bad_request(
+            "title-required",
+            "Title is required",
         ));
     }
     if question.description.is_empty() {
-        return Err(handlers_inner::HandlerError::BadRequest(
-            "Description is required".to_string(),
+        return Err(HandlerError::bad_request(
+            "description-required",
+            "Description is required",
         ));
     }
-    let question_detail = questions_dao.create_question(question).await;
+    let question_detail = questions_dao
+        .create_question(question, user.user_uuid)
+        .await;
     match question_detail {
-        Ok(question_detail) => Ok(Json(QuestionDetail {
-            question_uuid: question_detail.question_uuid,
-            title: question_detail.title,
-            description: question_detail.description,
-            created_at: question_detail.created_at,
-        })),
-        Err(e) => Err(handlers_inner::HandlerError::InternalError(e.to_string())),
+        Ok(mut question_detail) => {
+            question_detail.question_uuid = shortid::encode_str(&question_detail.question_uuid);
+            question_detail.author_uuid = shortid::encode_str(&question_detail.author_uuid);
+            Ok(Json(question_detail))
+        }
+        Err(e) => Err(HandlerError::internal("internal-error", e.to_string())),
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/questions",
+    params(Pagination),
+    responses(
+        (status = 200, description = "Page of questions", body = QuestionPage),
+        (status = 400, description = "Invalid pagination parameters"),
+        (status = 500, description = "Internal server error"),
+    )
+)]
 pub async fn read_questions(
     State(AppState { questions_dao, .. }): State<AppState>,
-) -> Result<Json<Vec<QuestionDetail>>, handlers_inner::HandlerError> {
-    let questions_detail = questions_dao.get_questions().await;
-    match questions_detail {
-        Ok(questions_detail) => Ok(Json(questions_detail)),
-        Err(e) => Err(handlers_inner::HandlerError::InternalError(e.to_string())),
+    Query(pagination): Query<Pagination>,
+) -> Result<Json<QuestionPage>, HandlerError> {
+    if let Some(sort) = &pagination.sort {
+        if sort != "created_at" {
+            return Err(HandlerError::bad_request(
+                "invalid-sort",
+                "Unsupported sort field",
+            ));
+        }
+    }
+    let questions_page = questions_dao.get_questions(pagination).await;
+    match questions_page {
+        Ok(mut questions_page) => {
+            for question_detail in &mut questions_page.items {
+                question_detail.question_uuid =
+                    shortid::encode_str(&question_detail.question_uuid);
+                question_detail.author_uuid = shortid::encode_str(&question_detail.author_uuid);
+            }
+            Ok(Json(questions_page))
+        }
+        Err(DBError::InvalidUUID(msg)) => Err(HandlerError::bad_request("invalid-cursor", msg)),
+        Err(e) => Err(HandlerError::internal("internal-error", e.to_string())),
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/question",
+    request_body = QuestionId,
+    responses(
+        (status = 200, description = "Question deleted"),
+        (status = 400, description = "Invalid question UUID"),
+        (status = 500, description = "Internal server error"),
+    )
+)]
 pub async fn delete_question(
     State(AppState { questions_dao, .. }): State<AppState>,
+    RequireUser(user): RequireUser,
     Json(question_uuid): Json<QuestionId>,
-) -> Result<(), handlers_inner::HandlerError> {
+) -> Result<(), HandlerError> {
     if question_uuid.question_uuid.is_empty() {
-        return Err(handlers_inner::HandlerError::BadRequest(
-            "Question UUID is required".to_string(),
-        ));
-    }
-    if !Uuid::parse_str(&question_uuid.question_uuid).is_ok() {
-        return Err(handlers_inner::HandlerError::BadRequest(
-            "Invalid question UUID".to_string(),
+        return Err(HandlerError::bad_request(
+            "question-uuid-required",
+            "Question UUID is required",
         ));
     }
+    let question_uuid = shortid::decode_to_string(&question_uuid.question_uuid)
+        .map_err(|_| HandlerError::bad_request("invalid-question-uuid", "Invalid question UUID"))?;
     let result = questions_dao
-        .delete_question(question_uuid.question_uuid)
+        .delete_question(question_uuid, user.user_uuid)
         .await;
     match result {
         Ok(_) => Ok(()),
-        Err(e) => Err(handlers_inner::HandlerError::InternalError(e.to_string())),
+        Err(DBError::NotFound(msg)) => Err(HandlerError::not_found("question-not-found", msg)),
+        Err(DBError::InvalidUUID(msg)) => {
+            Err(HandlerError::bad_request("invalid-question-uuid", msg))
+        }
+        Err(DBError::Conflict(msg)) => Err(HandlerError::internal("internal-error", msg)),
+        Err(DBError::Other(e)) => Err(HandlerError::internal("internal-error", e.to_string())),
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/answer",
+    request_body = Answer,
+    responses(
+        (status = 200, description = "Answer created", body = AnswerDetail),
+        (status = 400, description = "Invalid answer payload"),
+        (status = 500, description = "Internal server error"),
+    )
+)]
 pub async fn create_answer(
-    State(AppState { answers_dao, .. }): State<AppState>,
+    State(AppState {
+        answers_dao,
+        jobs_dao,
+        ..
+    }): State<AppState>,
+    RequireUser(user): RequireUser,
     Json(answer): Json<Answer>,
-) -> Result<Json<AnswerDetail>, handlers_inner::HandlerError> {
+) -> Result<Json<AnswerDetail>, HandlerError> {
     if answer.content.is_empty() {
-        return Err(handlers_inner::HandlerError::BadRequest(
-            "Content is required".to_string(),
-        ));
-    }
-    if !Uuid::parse_str(&answer.question_uuid).is_ok() {
-        return Err(handlers_inner::HandlerError::BadRequest(
-            "Invalid question UUID".to_string(),
+        return Err(HandlerError::bad_request(
+            "content-required",
+            "Content is required",
         ));
     }
-    let answer_detail = answers_dao.create_answer(answer).await;
+    let question_uuid = shortid::decode_to_string(&answer.question_uuid)
+        .map_err(|_| HandlerError::bad_request("invalid-question-uuid", "Invalid question UUID"))?;
+    let answer = Answer {
+        question_uuid,
+        ..answer
+    };
+    let answer_detail = answers_dao.create_answer(answer, user.user_uuid).await;
     match answer_detail {
-        Ok(answer_detail) => Ok(Json(answer_detail)),
-        Err(e) => Err(handlers_inner::HandlerError::InternalError(e.to_string())),
+        Ok(mut answer_detail) => {
+            let payload = serde_json::json!({
+                "type": "answer_created",
+                "question_uuid": answer_detail.question_uuid,
+                "answer_uuid": answer_detail.answer_uuid,
+            });
+            if let Err(e) = jobs_dao
+                .enqueue(NOTIFICATION_QUEUE.to_string(), payload)
+                .await
+            {
+                log::error!("Failed to enqueue answer notification job: {}", e);
+            }
+            answer_detail.question_uuid = shortid::encode_str(&answer_detail.question_uuid);
+            answer_detail.answer_uuid = shortid::encode_str(&answer_detail.answer_uuid);
+            answer_detail.author_uuid = shortid::encode_str(&answer_detail.author_uuid);
+            Ok(Json(answer_detail))
+        }
+        Err(DBError::InvalidUUID(msg)) => {
+            Err(HandlerError::bad_request("invalid-question-uuid", msg))
+        }
+        Err(DBError::NotFound(msg)) => Err(HandlerError::not_found("question-not-found", msg)),
+        Err(DBError::Conflict(msg)) => Err(HandlerError::internal("internal-error", msg)),
+        Err(DBError::Other(e)) => Err(HandlerError::internal("internal-error", e.to_string())),
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/answers",
+    params(Pagination),
+    request_body = QuestionId,
+    responses(
+        (status = 200, description = "Page of answers", body = AnswerPage),
+        (status = 400, description = "Invalid question UUID or pagination parameters"),
+        (status = 500, description = "Internal server error"),
+    )
+)]
 pub async fn read_answers(
     State(AppState { answers_dao, .. }): State<AppState>,
+    Query(pagination): Query<Pagination>,
     Json(question_uuid): Json<QuestionId>,
-) -> Result<Json<Vec<AnswerDetail>>, handlers_inner::HandlerError> {
+) -> Result<Json<AnswerPage>, HandlerError> {
     if question_uuid.question_uuid.is_empty() {
-        return Err(handlers_inner::HandlerError::BadRequest(
-            "Question UUID is required".to_string(),
+        return Err(HandlerError::bad_request(
+            "question-uuid-required",
+            "Question UUID is required",
         ));
     }
-    if !Uuid::parse_str(&question_uuid.question_uuid).is_ok() {
-        return Err(handlers_inner::HandlerError::BadRequest(
-            "Invalid question UUID".to_string(),
-        ));
+    let question_uuid = shortid::decode_to_string(&question_uuid.question_uuid)
+        .map_err(|_| HandlerError::bad_request("invalid-question-uuid", "Invalid question UUID"))?;
+    if let Some(sort) = &pagination.sort {
+        if sort != "created_at" {
+            return Err(HandlerError::bad_request(
+                "invalid-sort",
+                "Unsupported sort field",
+            ));
+        }
     }
-    let answer_detail = answers_dao.get_answers(question_uuid.question_uuid).await;
-    match answer_detail {
-        Ok(answer_detail) => Ok(Json(answer_detail)),
-        Err(e) => Err(handlers_inner::HandlerError::InternalError(e.to_string())),
+    let answer_page = answers_dao.get_answers(question_uuid, pagination).await;
+    match answer_page {
+        Ok(mut answer_page) => {
+            for answer_detail in &mut answer_page.items {
+                answer_detail.question_uuid = shortid::encode_str(&answer_detail.question_uuid);
+                answer_detail.answer_uuid = shortid::encode_str(&answer_detail.answer_uuid);
+                answer_detail.author_uuid = shortid::encode_str(&answer_detail.author_uuid);
+            }
+            Ok(Json(answer_page))
+        }
+        Err(DBError::InvalidUUID(msg)) => Err(HandlerError::bad_request("invalid-cursor", msg)),
+        Err(e) => Err(HandlerError::internal("internal-error", e.to_string())),
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/answer",
+    request_body = AnswerId,
+    responses(
+        (status = 200, description = "Answer deleted"),
+        (status = 400, description = "Invalid answer UUID"),
+        (status = 500, description = "Internal server error"),
+    )
+)]
 pub async fn delete_answer(
     State(AppState { answers_dao, .. }): State<AppState>,
+    RequireUser(user): RequireUser,
     Json(answer_uuid): Json<AnswerId>,
-) -> Result<(), handlers_inner::HandlerError> {
+) -> Result<(), HandlerError> {
     if answer_uuid.answer_uuid.is_empty() {
-        return Err(handlers_inner::HandlerError::BadRequest(
-            "Answer UUID is required".to_string(),
+        return Err(HandlerError::bad_request(
+            "answer-uuid-required",
+            "Answer UUID is required",
         ));
     }
-    if !Uuid::parse_str(&answer_uuid.answer_uuid).is_ok() {
-        return Err(handlers_inner::HandlerError::BadRequest(
-            "Invalid answer UUID".to_string(),
-        ));
-    }
-    let result = answers_dao.delete_answer(answer_uuid.answer_uuid).await;
+    let answer_uuid = shortid::decode_to_string(&answer_uuid.answer_uuid)
+        .map_err(|_| HandlerError::bad_request("invalid-answer-uuid", "Invalid answer UUID"))?;
+    let result = answers_dao
+        .delete_answer(answer_uuid, user.user_uuid)
+        .await;
     match result {
         Ok(_) => Ok(()),
-        Err(e) => Err(handlers_inner::HandlerError::InternalError(e.to_string())),
+        Err(DBError::NotFound(msg)) => Err(HandlerError::not_found("answer-not-found", msg)),
+        Err(DBError::InvalidUUID(msg)) => {
+            Err(HandlerError::bad_request("invalid-answer-uuid", msg))
+        }
+        Err(DBError::Conflict(msg)) => Err(HandlerError::internal("internal-error", msg)),
+        Err(DBError::Other(e)) => Err(HandlerError::internal("internal-error", e.to_string())),
     }
 }
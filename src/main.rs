@@ -5,23 +5,107 @@ use axum::{
     routing::{delete, get, post},
 };
 use dotenvy::dotenv;
+use sqlx::PgPool;
 use sqlx::postgres::PgPoolOptions;
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
 
 use persistance::{
     answers_dao::{AnswersDao, AnswersDaoImpl},
+    jobs_dao::{JobsDao, JobsDaoImpl, NOTIFICATION_QUEUE},
     questions_dao::{QuestionsDao, QuestionsDaoImpl},
+    sessions_dao::{SessionsDao, SessionsDaoImpl},
+    users_dao::{UsersDao, UsersDaoImpl},
 };
 
+mod config;
+mod cursor;
 mod handlers;
 mod models;
+mod openapi;
 mod persistance;
+mod shortid;
+
+use config::Config;
 
 use handlers::*;
+use openapi::ApiDoc;
 
 #[derive(Clone)]
 pub struct AppState {
     pub questions_dao: Arc<dyn QuestionsDao + Send + Sync>,
     pub answers_dao: Arc<dyn AnswersDao + Send + Sync>,
+    pub users_dao: Arc<dyn UsersDao + Send + Sync>,
+    pub sessions_dao: Arc<dyn SessionsDao + Send + Sync>,
+    pub jobs_dao: Arc<dyn JobsDao + Send + Sync>,
+    pub db_pool: PgPool,
+}
+
+/// Runs the side effect described by a notification job's `type` field.
+async fn dispatch_notification_job(
+    questions_dao: &(dyn QuestionsDao + Send + Sync),
+    job: &models::Job,
+) {
+    match job.job.get("type").and_then(|t| t.as_str()) {
+        Some("answer_created") => {
+            let question_uuid = job.job.get("question_uuid").and_then(|v| v.as_str());
+            let answer_uuid = job.job.get("answer_uuid").and_then(|v| v.as_str());
+            let (Some(question_uuid), Some(answer_uuid)) = (question_uuid, answer_uuid) else {
+                log::error!("answer_created job {} is missing ids: {}", job.id, job.job);
+                return;
+            };
+            match questions_dao.get_question(question_uuid.to_string()).await {
+                Ok(Some(question)) => {
+                    log::info!(
+                        "Notifying author {} of question {} about new answer {}",
+                        question.author_uuid,
+                        question_uuid,
+                        answer_uuid
+                    );
+                }
+                Ok(None) => {
+                    log::warn!(
+                        "Skipping notification for answer {}: question {} no longer exists",
+                        answer_uuid,
+                        question_uuid
+                    );
+                }
+                Err(e) => log::error!("Failed to look up question {}: {}", question_uuid, e),
+            }
+        }
+        other => log::error!("Unknown notification job type {:?} for job {}", other, job.id),
+    }
+}
+
+fn spawn_job_worker(
+    jobs_dao: Arc<dyn JobsDao + Send + Sync>,
+    questions_dao: Arc<dyn QuestionsDao + Send + Sync>,
+) {
+    tokio::spawn(async move {
+        match jobs_dao.requeue_stuck(NOTIFICATION_QUEUE.to_string()).await {
+            Ok(0) => {}
+            Ok(n) => log::warn!("Requeued {} stuck job(s) from a previous run", n),
+            Err(e) => log::error!("Failed to requeue stuck jobs: {}", e),
+        }
+
+        loop {
+            match jobs_dao.claim_one(NOTIFICATION_QUEUE.to_string()).await {
+                Ok(Some(job)) => {
+                    dispatch_notification_job(questions_dao.as_ref(), &job).await;
+                    if let Err(e) = jobs_dao.complete(job.id).await {
+                        log::error!("Failed to mark job complete: {}", e);
+                    }
+                }
+                Ok(None) => {
+                    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+                }
+                Err(e) => {
+                    log::error!("Failed to claim job: {}", e);
+                    tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+                }
+            }
+        }
+    });
 }
 
 #[tokio::main]
@@ -29,18 +113,35 @@ async fn main() {
     pretty_env_logger::init();
     dotenv().ok();
 
+    let config = Config::load();
+
     let pool = PgPoolOptions::new()
-        .max_connections(5)
-        .connect(&std::env::var("DATABASE_URL").expect("DATABASE_URL must be set."))
+        .max_connections(config.max_connections)
+        .connect(&config.database_url)
         .await
         .expect("Failed to create Postgres connection pool!");
 
-    let questions_dao = QuestionsDaoImpl::new(pool.clone());
+    sqlx::migrate!()
+        .run(&pool)
+        .await
+        .expect("Failed to run database migrations! Check for a dirty migration state.");
+
+    let questions_dao: Arc<dyn QuestionsDao + Send + Sync> =
+        Arc::new(QuestionsDaoImpl::new(pool.clone()));
     let answers_dao = AnswersDaoImpl::new(pool.clone());
+    let users_dao = UsersDaoImpl::new(pool.clone());
+    let sessions_dao = SessionsDaoImpl::new(pool.clone());
+    let jobs_dao: Arc<dyn JobsDao + Send + Sync> = Arc::new(JobsDaoImpl::new(pool.clone()));
+
+    spawn_job_worker(jobs_dao.clone(), questions_dao.clone());
 
     let app_state = AppState {
-        questions_dao: Arc::new(questions_dao),
+        questions_dao,
         answers_dao: Arc::new(answers_dao),
+        users_dao: Arc::new(users_dao),
+        sessions_dao: Arc::new(sessions_dao),
+        jobs_dao,
+        db_pool: pool.clone(),
     };
 
     let app = Router::new()
@@ -50,9 +151,15 @@ async fn main() {
         .route("/answer", post(create_answer))
         .route("/answers", get(read_answers))
         .route("/answer", delete(delete_answer))
+        .route("/register", post(auth::register))
+        .route("/login", post(auth::login))
+        .route("/logout", post(auth::logout))
+        .route("/health", get(health::liveness))
+        .route("/health/db", get(health::readiness))
+        .merge(SwaggerUi::new("/swagger-ui").url("/api-doc/openapi.json", ApiDoc::openapi()))
         .with_state(app_state);
 
-    let listener = tokio::net::TcpListener::bind("127.0.0.1:8000")
+    let listener = tokio::net::TcpListener::bind(&config.bind_address)
         .await
         .unwrap();
 
@@ -0,0 +1,156 @@
+use std::error::Error;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+pub mod postgres_error_codes {
+    pub const FOREIGN_KEY_VIOLATION: &str = "23503";
+    pub const UNIQUE_VIOLATION: &str = "23505";
+}
+
+#[derive(Debug)]
+pub enum DBError {
+    InvalidUUID(String),
+    NotFound(String),
+    Conflict(String),
+    Other(Box<dyn Error + Send + Sync>),
+}
+
+impl fmt::Display for DBError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DBError::InvalidUUID(s) => write!(f, "Invalid UUID provided: {}", s),
+            DBError::NotFound(s) => write!(f, "{}", s),
+            DBError::Conflict(s) => write!(f, "{}", s),
+            DBError::Other(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for DBError {}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Question {
+    pub title: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuestionDetail {
+    pub question_uuid: String,
+    pub author_uuid: String,
+    pub title: String,
+    pub description: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct QuestionPage {
+    pub items: Vec<QuestionDetail>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuestionId {
+    pub question_uuid: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Answer {
+    pub question_uuid: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AnswerDetail {
+    pub answer_uuid: String,
+    pub question_uuid: String,
+    pub author_uuid: String,
+    pub content: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AnswerPage {
+    pub items: Vec<AnswerDetail>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AnswerId {
+    pub answer_uuid: String,
+}
+
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct Pagination {
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+    pub sort: Option<String>,
+}
+
+impl Pagination {
+    pub const DEFAULT_LIMIT: i64 = 20;
+    pub const MAX_LIMIT: i64 = 100;
+
+    pub fn limit(&self) -> i64 {
+        self.limit
+            .unwrap_or(Self::DEFAULT_LIMIT)
+            .clamp(1, Self::MAX_LIMIT)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct User {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserDetail {
+    pub user_uuid: String,
+    pub username: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct UserRecord {
+    pub user_uuid: String,
+    pub username: String,
+    pub password_hash: String,
+    pub created_at: String,
+}
+
+impl From<UserRecord> for UserDetail {
+    fn from(record: UserRecord) -> Self {
+        UserDetail {
+            user_uuid: record.user_uuid,
+            username: record.username,
+            created_at: record.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub session_id: String,
+    pub user_uuid: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "job_status", rename_all = "lowercase")]
+pub enum JobStatus {
+    New,
+    Running,
+}
+
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub id: String,
+    pub queue: String,
+    pub job: serde_json::Value,
+    pub status: JobStatus,
+    pub created_at: String,
+    pub updated_at: String,
+}
@@ -0,0 +1,42 @@
+use utoipa::OpenApi;
+
+use crate::handlers;
+use crate::models;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handlers::create_question,
+        handlers::read_questions,
+        handlers::delete_question,
+        handlers::create_answer,
+        handlers::read_answers,
+        handlers::delete_answer,
+        handlers::auth::register,
+        handlers::auth::login,
+        handlers::auth::logout,
+        handlers::health::liveness,
+        handlers::health::readiness,
+    ),
+    components(schemas(
+        models::Question,
+        models::QuestionDetail,
+        models::QuestionPage,
+        models::QuestionId,
+        models::Answer,
+        models::AnswerDetail,
+        models::AnswerPage,
+        models::AnswerId,
+        models::User,
+        models::UserDetail,
+        handlers::health::HealthStatus,
+        handlers::health::ReadinessStatus,
+    )),
+    tags(
+        (name = "questions", description = "Question management endpoints"),
+        (name = "answers", description = "Answer management endpoints"),
+        (name = "auth", description = "Registration, login, and session endpoints"),
+        (name = "health", description = "Liveness and readiness probes"),
+    )
+)]
+pub struct ApiDoc;
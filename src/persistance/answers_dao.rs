@@ -1,13 +1,22 @@
 use async_trait::async_trait;
 use sqlx::{PgPool, types::Uuid};
 
-use crate::models::{Answer, AnswerDetail, DBError, postgres_error_codes};
+use crate::cursor::Cursor;
+use crate::models::{Answer, AnswerDetail, AnswerPage, DBError, Pagination, postgres_error_codes};
 
 #[async_trait]
 pub trait AnswersDao {
-    async fn create_answer(&self, answer: Answer) -> Result<AnswerDetail, DBError>;
-    async fn delete_answer(&self, answer_uuid: String) -> Result<(), DBError>;
-    async fn get_answers(&self, question_uuid: String) -> Result<Vec<AnswerDetail>, DBError>;
+    async fn create_answer(
+        &self,
+        answer: Answer,
+        author_uuid: String,
+    ) -> Result<AnswerDetail, DBError>;
+    async fn delete_answer(&self, answer_uuid: String, author_uuid: String) -> Result<(), DBError>;
+    async fn get_answers(
+        &self,
+        question_uuid: String,
+        pagination: Pagination,
+    ) -> Result<AnswerPage, DBError>;
 }
 
 pub struct AnswersDaoImpl {
@@ -22,7 +31,11 @@ impl AnswersDaoImpl {
 
 #[async_trait]
 impl AnswersDao for AnswersDaoImpl {
-    async fn create_answer(&self, answer: Answer) -> Result<AnswerDetail, DBError> {
+    async fn create_answer(
+        &self,
+        answer: Answer,
+        author_uuid: String,
+    ) -> Result<AnswerDetail, DBError> {
         // Use the `sqlx::types::Uuid::parse_str` method to parse the `question_uuid` field
         // in `Answer` into a `Uuid` type.
         // parse_str docs: https://docs.rs/sqlx/latest/sqlx/types/struct.Uuid.html#method.parse_str
@@ -31,21 +44,24 @@ impl AnswersDao for AnswersDaoImpl {
         // and early return from this function.
         let uuid = Uuid::parse_str(&answer.question_uuid)
             .map_err(|e| DBError::InvalidUUID(e.to_string()))?;
+        let author_uuid =
+            Uuid::parse_str(&author_uuid).map_err(|e| DBError::InvalidUUID(e.to_string()))?;
 
         // Make a database query to insert a new answer.
         // Here is the SQL query:
         // ```
-        // INSERT INTO answers ( question_uuid, content )
-        // VALUES ( $1, $2 )
+        // INSERT INTO answers ( question_uuid, author_uuid, content )
+        // VALUES ( $1, $2, $3 )
         // RETURNING *
         // ```
         // If executing the query results in an error, check to see if
         // the error code matches `postgres_error_codes::FOREIGN_KEY_VIOLATION`.
-        // If so early return the `DBError::InvalidUUID` error. Otherwise early return
+        // If so early return the `DBError::NotFound` error. Otherwise early return
         // the `DBError::Other` error.
         let record = sqlx::query!(
-            "INSERT INTO answers (question_uuid, content) VALUES ($1, $2) RETURNING *",
+            "INSERT INTO answers (question_uuid, author_uuid, content) VALUES ($1, $2, $3) RETURNING *",
             uuid,
+            author_uuid,
             answer.content
         )
         .fetch_one(&self.db)
@@ -53,7 +69,7 @@ impl AnswersDao for AnswersDaoImpl {
         .map_err(|e| {
             if let Some(code) = e.as_database_error().and_then(|db_err| db_err.code()) {
                 if code == postgres_error_codes::FOREIGN_KEY_VIOLATION {
-                    return DBError::InvalidUUID("Question not found".to_string());
+                    return DBError::NotFound("Question not found".to_string());
                 }
             }
             DBError::Other(e.into())
@@ -63,12 +79,13 @@ impl AnswersDao for AnswersDaoImpl {
         Ok(AnswerDetail {
             answer_uuid: record.answer_uuid.to_string(),
             question_uuid: record.question_uuid.to_string(),
+            author_uuid: record.author_uuid.to_string(),
             content: record.content,
             created_at: record.created_at.to_string(),
         })
     }
 
-    async fn delete_answer(&self, answer_uuid: String) -> Result<(), DBError> {
+    async fn delete_answer(&self, answer_uuid: String, author_uuid: String) -> Result<(), DBError> {
         // Use the `sqlx::types::Uuid::parse_str` method to parse `answer_uuid` into a `Uuid` type.
         // parse_str docs: https://docs.rs/sqlx/latest/sqlx/types/struct.Uuid.html#method.parse_str
         //
@@ -76,23 +93,37 @@ impl AnswersDao for AnswersDaoImpl {
         // and early return from this function.
         let uuid =
             Uuid::parse_str(&answer_uuid).map_err(|e| DBError::InvalidUUID(e.to_string()))?;
+        let author_uuid =
+            Uuid::parse_str(&author_uuid).map_err(|e| DBError::InvalidUUID(e.to_string()))?;
 
-        // Make a database query to delete an answer given the answer uuid.
+        // Make a database query to delete an answer given the answer uuid, scoped to its author.
         // Here is the SQL query:
         // ```
-        // DELETE FROM answers WHERE answer_uuid = $1
+        // DELETE FROM answers WHERE answer_uuid = $1 AND author_uuid = $2
         // ```
         // If executing the query results in an error, map that error
         // to a `DBError::Other` error and early return from this function.
-        sqlx::query!("DELETE FROM answers WHERE answer_uuid = $1", uuid)
-            .execute(&self.db)
-            .await
-            .map_err(|e| DBError::Other(e.into()))?;
+        let result = sqlx::query!(
+            "DELETE FROM answers WHERE answer_uuid = $1 AND author_uuid = $2",
+            uuid,
+            author_uuid
+        )
+        .execute(&self.db)
+        .await
+        .map_err(|e| DBError::Other(e.into()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DBError::NotFound("Answer not found".to_string()));
+        }
 
         Ok(())
     }
 
-    async fn get_answers(&self, question_uuid: String) -> Result<Vec<AnswerDetail>, DBError> {
+    async fn get_answers(
+        &self,
+        question_uuid: String,
+        pagination: Pagination,
+    ) -> Result<AnswerPage, DBError> {
         // Use the `sqlx::types::Uuid::parse_str` method to parse `question_uuid` into a `Uuid` type.
         // parse_str docs: https://docs.rs/sqlx/latest/sqlx/types/struct.Uuid.html#method.parse_str
         //
@@ -101,29 +132,60 @@ impl AnswersDao for AnswersDaoImpl {
         let uuid =
             Uuid::parse_str(&question_uuid).map_err(|e| DBError::InvalidUUID(e.to_string()))?;
 
-        // Make a database query to get all answers associated with a question uuid.
-        // Here is the SQL query:
-        // ```
-        // SELECT * FROM answers WHERE question_uuid = $1
-        // ```
-        // If executing the query results in an error, map that error
-        // to a `DBError::Other` error and early return from this function.
-        let records = sqlx::query!("SELECT * FROM answers WHERE question_uuid = $1", uuid)
-            .fetch_all(&self.db)
-            .await
-            .map_err(|e| DBError::Other(e.into()))?;
+        // Make a database query to get all answers associated with a question uuid,
+        // paginated by a `created_at` keyset cursor instead of OFFSET.
+        let limit = pagination.limit();
+
+        let records = match pagination.cursor {
+            Some(cursor) => {
+                let cursor = Cursor::decode(&cursor)?;
+                sqlx::query!(
+                    "SELECT * FROM answers WHERE question_uuid = $1 AND (created_at, answer_uuid) < ($2, $3)
+                     ORDER BY created_at DESC, answer_uuid DESC LIMIT $4",
+                    uuid,
+                    cursor.created_at,
+                    cursor.uuid,
+                    limit
+                )
+                .fetch_all(&self.db)
+                .await
+            }
+            None => {
+                sqlx::query!(
+                    "SELECT * FROM answers WHERE question_uuid = $1 ORDER BY created_at DESC, answer_uuid DESC LIMIT $2",
+                    uuid,
+                    limit
+                )
+                .fetch_all(&self.db)
+                .await
+            }
+        }
+        .map_err(|e| DBError::Other(e.into()))?;
+
+        let next_cursor = if records.len() as i64 == limit {
+            records.last().map(|record| {
+                Cursor {
+                    created_at: record.created_at,
+                    uuid: record.answer_uuid,
+                }
+                .encode()
+            })
+        } else {
+            None
+        };
 
         // Iterate over `records` and map each record to a `AnswerDetail` type
-        let answers = records
+        let items = records
             .into_iter()
             .map(|record| AnswerDetail {
                 answer_uuid: record.answer_uuid.to_string(),
                 question_uuid: record.question_uuid.to_string(),
+                author_uuid: record.author_uuid.to_string(),
                 content: record.content,
                 created_at: record.created_at.to_string(),
             })
             .collect();
 
-        Ok(answers)
+        Ok(AnswerPage { items, next_cursor })
     }
 }
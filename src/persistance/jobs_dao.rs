@@ -0,0 +1,119 @@
+use async_trait::async_trait;
+use sqlx::{PgPool, types::Uuid};
+
+use crate::models::{DBError, Job, JobStatus};
+
+pub const NOTIFICATION_QUEUE: &str = "notifications";
+
+/// How long a claimed job may sit in `running` before it's considered
+/// abandoned by a crashed worker and safe to recover. Must comfortably
+/// exceed how long a live worker takes to process one job, or `requeue_stuck`
+/// will steal work another instance is still processing.
+const JOB_LEASE_SECONDS: f64 = 300.0;
+
+#[async_trait]
+pub trait JobsDao {
+    async fn enqueue(&self, queue: String, payload: serde_json::Value) -> Result<Job, DBError>;
+    async fn claim_one(&self, queue: String) -> Result<Option<Job>, DBError>;
+    async fn complete(&self, id: String) -> Result<(), DBError>;
+    async fn requeue_stuck(&self, queue: String) -> Result<u64, DBError>;
+}
+
+pub struct JobsDaoImpl {
+    db: PgPool,
+}
+
+impl JobsDaoImpl {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl JobsDao for JobsDaoImpl {
+    async fn enqueue(&self, queue: String, payload: serde_json::Value) -> Result<Job, DBError> {
+        let record = sqlx::query!(
+            r#"
+            INSERT INTO job_queue (queue, job, status)
+            VALUES ($1, $2, 'new')
+            RETURNING id, queue, job, status AS "status: JobStatus", created_at, updated_at
+            "#,
+            queue,
+            payload
+        )
+        .fetch_one(&self.db)
+        .await
+        .map_err(|e| DBError::Other(e.into()))?;
+
+        Ok(Job {
+            id: record.id.to_string(),
+            queue: record.queue,
+            job: record.job,
+            status: record.status,
+            created_at: record.created_at.to_string(),
+            updated_at: record.updated_at.to_string(),
+        })
+    }
+
+    async fn claim_one(&self, queue: String) -> Result<Option<Job>, DBError> {
+        // Select the oldest `new` row with `FOR UPDATE SKIP LOCKED` so concurrent
+        // workers never block on or double-claim the same job, then flip it to `running`.
+        let record = sqlx::query!(
+            r#"
+            UPDATE job_queue
+            SET status = 'running', updated_at = now()
+            WHERE id = (
+                SELECT id FROM job_queue
+                WHERE queue = $1 AND status = 'new'
+                ORDER BY created_at
+                FOR UPDATE SKIP LOCKED
+                LIMIT 1
+            )
+            RETURNING id, queue, job, status AS "status: JobStatus", created_at, updated_at
+            "#,
+            queue
+        )
+        .fetch_optional(&self.db)
+        .await
+        .map_err(|e| DBError::Other(e.into()))?;
+
+        Ok(record.map(|record| Job {
+            id: record.id.to_string(),
+            queue: record.queue,
+            job: record.job,
+            status: record.status,
+            created_at: record.created_at.to_string(),
+            updated_at: record.updated_at.to_string(),
+        }))
+    }
+
+    async fn complete(&self, id: String) -> Result<(), DBError> {
+        let uuid = Uuid::parse_str(&id).map_err(|e| DBError::InvalidUUID(e.to_string()))?;
+
+        sqlx::query!("DELETE FROM job_queue WHERE id = $1", uuid)
+            .execute(&self.db)
+            .await
+            .map_err(|e| DBError::Other(e.into()))?;
+
+        Ok(())
+    }
+
+    async fn requeue_stuck(&self, queue: String) -> Result<u64, DBError> {
+        // A row stays `running` for the whole time its worker is actively processing
+        // it, so only rows whose lease has actually expired are stuck; anything more
+        // recent may still be owned by another live worker instance and must be left
+        // alone, or it would get double-processed.
+        let result = sqlx::query!(
+            "UPDATE job_queue SET status = 'new'
+             WHERE queue = $1 AND status = 'running'
+             AND updated_at < now() - make_interval(secs => $2)",
+            queue,
+            JOB_LEASE_SECONDS
+        )
+        .execute(&self.db)
+        .await
+        .map_err(|e| DBError::Other(e.into()))?;
+
+        Ok(result.rows_affected())
+    }
+}
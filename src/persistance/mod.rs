@@ -0,0 +1,5 @@
+pub mod answers_dao;
+pub mod jobs_dao;
+pub mod questions_dao;
+pub mod sessions_dao;
+pub mod users_dao;
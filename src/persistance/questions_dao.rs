@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use sqlx::{PgPool, types::Uuid};
+
+use crate::cursor::Cursor;
+use crate::models::{DBError, Pagination, Question, QuestionDetail, QuestionPage};
+
+#[async_trait]
+pub trait QuestionsDao {
+    async fn create_question(
+        &self,
+        question: Question,
+        author_uuid: String,
+    ) -> Result<QuestionDetail, DBError>;
+    async fn delete_question(&self, question_uuid: String, author_uuid: String)
+    -> Result<(), DBError>;
+    async fn get_questions(&self, pagination: Pagination) -> Result<QuestionPage, DBError>;
+    async fn get_question(&self, question_uuid: String) -> Result<Option<QuestionDetail>, DBError>;
+}
+
+pub struct QuestionsDaoImpl {
+    db: PgPool,
+}
+
+impl QuestionsDaoImpl {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl QuestionsDao for QuestionsDaoImpl {
+    async fn create_question(
+        &self,
+        question: Question,
+        author_uuid: String,
+    ) -> Result<QuestionDetail, DBError> {
+        let author_uuid =
+            Uuid::parse_str(&author_uuid).map_err(|e| DBError::InvalidUUID(e.to_string()))?;
+
+        let record = sqlx::query!(
+            "INSERT INTO questions (author_uuid, title, description) VALUES ($1, $2, $3) RETURNING *",
+            author_uuid,
+            question.title,
+            question.description
+        )
+        .fetch_one(&self.db)
+        .await
+        .map_err(|e| DBError::Other(e.into()))?;
+
+        Ok(QuestionDetail {
+            question_uuid: record.question_uuid.to_string(),
+            author_uuid: record.author_uuid.to_string(),
+            title: record.title,
+            description: record.description,
+            created_at: record.created_at.to_string(),
+        })
+    }
+
+    async fn delete_question(
+        &self,
+        question_uuid: String,
+        author_uuid: String,
+    ) -> Result<(), DBError> {
+        let uuid =
+            Uuid::parse_str(&question_uuid).map_err(|e| DBError::InvalidUUID(e.to_string()))?;
+        let author_uuid =
+            Uuid::parse_str(&author_uuid).map_err(|e| DBError::InvalidUUID(e.to_string()))?;
+
+        let result = sqlx::query!(
+            "DELETE FROM questions WHERE question_uuid = $1 AND author_uuid = $2",
+            uuid,
+            author_uuid
+        )
+        .execute(&self.db)
+        .await
+        .map_err(|e| DBError::Other(e.into()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DBError::NotFound("Question not found".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn get_questions(&self, pagination: Pagination) -> Result<QuestionPage, DBError> {
+        let limit = pagination.limit();
+
+        let records = match pagination.cursor {
+            Some(cursor) => {
+                let cursor = Cursor::decode(&cursor)?;
+                sqlx::query!(
+                    "SELECT * FROM questions WHERE (created_at, question_uuid) < ($1, $2)
+                     ORDER BY created_at DESC, question_uuid DESC LIMIT $3",
+                    cursor.created_at,
+                    cursor.uuid,
+                    limit
+                )
+                .fetch_all(&self.db)
+                .await
+            }
+            None => {
+                sqlx::query!(
+                    "SELECT * FROM questions ORDER BY created_at DESC, question_uuid DESC LIMIT $1",
+                    limit
+                )
+                .fetch_all(&self.db)
+                .await
+            }
+        }
+        .map_err(|e| DBError::Other(e.into()))?;
+
+        let next_cursor = if records.len() as i64 == limit {
+            records.last().map(|record| {
+                Cursor {
+                    created_at: record.created_at,
+                    uuid: record.question_uuid,
+                }
+                .encode()
+            })
+        } else {
+            None
+        };
+
+        let items = records
+            .into_iter()
+            .map(|record| QuestionDetail {
+                question_uuid: record.question_uuid.to_string(),
+                author_uuid: record.author_uuid.to_string(),
+                title: record.title,
+                description: record.description,
+                created_at: record.created_at.to_string(),
+            })
+            .collect();
+
+        Ok(QuestionPage { items, next_cursor })
+    }
+
+    async fn get_question(&self, question_uuid: String) -> Result<Option<QuestionDetail>, DBError> {
+        let uuid =
+            Uuid::parse_str(&question_uuid).map_err(|e| DBError::InvalidUUID(e.to_string()))?;
+
+        let record = sqlx::query!("SELECT * FROM questions WHERE question_uuid = $1", uuid)
+            .fetch_optional(&self.db)
+            .await
+            .map_err(|e| DBError::Other(e.into()))?;
+
+        Ok(record.map(|record| QuestionDetail {
+            question_uuid: record.question_uuid.to_string(),
+            author_uuid: record.author_uuid.to_string(),
+            title: record.title,
+            description: record.description,
+            created_at: record.created_at.to_string(),
+        }))
+    }
+}
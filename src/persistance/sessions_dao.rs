@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use sqlx::{PgPool, types::Uuid};
+
+use crate::models::{DBError, Session};
+
+/// How long a session stays valid after it is created.
+pub const SESSION_TTL_HOURS: i32 = 24;
+
+#[async_trait]
+pub trait SessionsDao {
+    async fn create_session(&self, user_uuid: String) -> Result<Session, DBError>;
+    async fn get_session(&self, session_id: String) -> Result<Option<Session>, DBError>;
+    async fn delete_session(&self, session_id: String) -> Result<(), DBError>;
+}
+
+pub struct SessionsDaoImpl {
+    db: PgPool,
+}
+
+impl SessionsDaoImpl {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl SessionsDao for SessionsDaoImpl {
+    async fn create_session(&self, user_uuid: String) -> Result<Session, DBError> {
+        let user_uuid =
+            Uuid::parse_str(&user_uuid).map_err(|e| DBError::InvalidUUID(e.to_string()))?;
+        let session_id = Uuid::new_v4();
+
+        let record = sqlx::query!(
+            "INSERT INTO sessions (session_id, user_uuid) VALUES ($1, $2) RETURNING *",
+            session_id,
+            user_uuid
+        )
+        .fetch_one(&self.db)
+        .await
+        .map_err(|e| DBError::Other(e.into()))?;
+
+        Ok(Session {
+            session_id: record.session_id.to_string(),
+            user_uuid: record.user_uuid.to_string(),
+            created_at: record.created_at.to_string(),
+        })
+    }
+
+    async fn get_session(&self, session_id: String) -> Result<Option<Session>, DBError> {
+        let session_id =
+            Uuid::parse_str(&session_id).map_err(|e| DBError::InvalidUUID(e.to_string()))?;
+
+        let record = sqlx::query!(
+            "SELECT * FROM sessions WHERE session_id = $1
+             AND created_at > now() - make_interval(hours => $2)",
+            session_id,
+            SESSION_TTL_HOURS
+        )
+        .fetch_optional(&self.db)
+        .await
+        .map_err(|e| DBError::Other(e.into()))?;
+
+        Ok(record.map(|record| Session {
+            session_id: record.session_id.to_string(),
+            user_uuid: record.user_uuid.to_string(),
+            created_at: record.created_at.to_string(),
+        }))
+    }
+
+    async fn delete_session(&self, session_id: String) -> Result<(), DBError> {
+        let session_id =
+            Uuid::parse_str(&session_id).map_err(|e| DBError::InvalidUUID(e.to_string()))?;
+
+        sqlx::query!("DELETE FROM sessions WHERE session_id = $1", session_id)
+            .execute(&self.db)
+            .await
+            .map_err(|e| DBError::Other(e.into()))?;
+
+        Ok(())
+    }
+}
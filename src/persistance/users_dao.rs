@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::models::{DBError, User, UserRecord};
+
+#[async_trait]
+pub trait UsersDao {
+    async fn create_user(&self, user: User, password_hash: String) -> Result<UserRecord, DBError>;
+    async fn get_user_by_username(&self, username: String) -> Result<Option<UserRecord>, DBError>;
+    async fn get_user_by_uuid(&self, user_uuid: String) -> Result<Option<UserRecord>, DBError>;
+}
+
+pub struct UsersDaoImpl {
+    db: PgPool,
+}
+
+impl UsersDaoImpl {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl UsersDao for UsersDaoImpl {
+    async fn create_user(&self, user: User, password_hash: String) -> Result<UserRecord, DBError> {
+        let record = sqlx::query!(
+            "INSERT INTO users (username, password_hash) VALUES ($1, $2) RETURNING *",
+            user.username,
+            password_hash
+        )
+        .fetch_one(&self.db)
+        .await
+        .map_err(|e| {
+            if let Some(code) = e.as_database_error().and_then(|db_err| db_err.code()) {
+                if code == crate::models::postgres_error_codes::UNIQUE_VIOLATION {
+                    return DBError::Conflict("Username already taken".to_string());
+                }
+            }
+            DBError::Other(e.into())
+        })?;
+
+        Ok(UserRecord {
+            user_uuid: record.user_uuid.to_string(),
+            username: record.username,
+            password_hash: record.password_hash,
+            created_at: record.created_at.to_string(),
+        })
+    }
+
+    async fn get_user_by_username(&self, username: String) -> Result<Option<UserRecord>, DBError> {
+        let record = sqlx::query!("SELECT * FROM users WHERE username = $1", username)
+            .fetch_optional(&self.db)
+            .await
+            .map_err(|e| DBError::Other(e.into()))?;
+
+        Ok(record.map(|record| UserRecord {
+            user_uuid: record.user_uuid.to_string(),
+            username: record.username,
+            password_hash: record.password_hash,
+            created_at: record.created_at.to_string(),
+        }))
+    }
+
+    async fn get_user_by_uuid(&self, user_uuid: String) -> Result<Option<UserRecord>, DBError> {
+        let uuid = sqlx::types::Uuid::parse_str(&user_uuid)
+            .map_err(|e| DBError::InvalidUUID(e.to_string()))?;
+
+        let record = sqlx::query!("SELECT * FROM users WHERE user_uuid = $1", uuid)
+            .fetch_optional(&self.db)
+            .await
+            .map_err(|e| DBError::Other(e.into()))?;
+
+        Ok(record.map(|record| UserRecord {
+            user_uuid: record.user_uuid.to_string(),
+            username: record.username,
+            password_hash: record.password_hash,
+            created_at: record.created_at.to_string(),
+        }))
+    }
+}
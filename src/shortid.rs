@@ -0,0 +1,84 @@
+use std::sync::OnceLock;
+
+use sqids::Sqids;
+use sqlx::types::Uuid;
+
+use crate::models::DBError;
+
+pub(crate) fn sqids() -> &'static Sqids {
+    static SQIDS: OnceLock<Sqids> = OnceLock::new();
+    SQIDS.get_or_init(|| Sqids::default())
+}
+
+/// Encodes a UUID into an opaque, URL-friendly short id for the API surface.
+pub fn encode(uuid: &Uuid) -> String {
+    let bytes = uuid.as_bytes();
+    let high = u64::from_be_bytes(bytes[0..8].try_into().unwrap());
+    let low = u64::from_be_bytes(bytes[8..16].try_into().unwrap());
+    sqids()
+        .encode(&[high, low])
+        .expect("uuid-derived numbers always encode")
+}
+
+/// Decodes a short id produced by [`encode`] back into the UUID it represents.
+pub fn decode(short_id: &str) -> Result<Uuid, DBError> {
+    let numbers = sqids().decode(short_id);
+    let [high, low]: [u64; 2] = numbers
+        .try_into()
+        .map_err(|_| DBError::InvalidUUID(format!("Invalid short id: {}", short_id)))?;
+
+    let mut bytes = [0u8; 16];
+    bytes[0..8].copy_from_slice(&high.to_be_bytes());
+    bytes[8..16].copy_from_slice(&low.to_be_bytes());
+    Ok(Uuid::from_bytes(bytes))
+}
+
+/// Convenience wrapper for encoding a UUID that is already stored as a `String`.
+pub fn encode_str(uuid: &str) -> String {
+    match Uuid::parse_str(uuid) {
+        Ok(uuid) => encode(&uuid),
+        Err(_) => uuid.to_string(),
+    }
+}
+
+/// Convenience wrapper for decoding a short id straight into the `String` form
+/// the DAO layer expects.
+pub fn decode_to_string(short_id: &str) -> Result<String, DBError> {
+    decode(short_id).map(|uuid| uuid.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_a_uuid() {
+        let uuid = Uuid::new_v4();
+        assert_eq!(decode(&encode(&uuid)).unwrap(), uuid);
+    }
+
+    #[test]
+    fn round_trips_the_nil_uuid() {
+        let uuid = Uuid::nil();
+        assert_eq!(decode(&encode(&uuid)).unwrap(), uuid);
+    }
+
+    #[test]
+    fn encode_str_and_decode_to_string_round_trip() {
+        let uuid = Uuid::new_v4();
+        let decoded = decode_to_string(&encode_str(&uuid.to_string())).unwrap();
+        assert_eq!(decoded, uuid.to_string());
+    }
+
+    #[test]
+    fn rejects_a_short_id_that_does_not_decode_to_two_numbers() {
+        // A sqid encoding a single number, not the two this codec expects.
+        let single_number_id = sqids().encode(&[42]).unwrap();
+        assert!(decode(&single_number_id).is_err());
+    }
+
+    #[test]
+    fn rejects_garbage_input() {
+        assert!(decode("not-a-valid-sqid!!").is_err());
+    }
+}